@@ -18,6 +18,9 @@ pub enum CWCmd {
         /// Template's version, using main branch if not specified
         #[clap(short, long)]
         version: Option<String>,
+        /// If set, also scaffold a separate `e2e` integration-test crate using cw-multi-test
+        #[clap(short, long)]
+        e2e: bool,
     },
     /// Build .wasm for storing contract code on the blockchain
     Build {
@@ -27,6 +30,35 @@ pub enum CWCmd {
         /// Option for m1 user for wasm optimization, FOR TESTING ONLY, PRODUCTION BUILD SHOULD USE INTEL BUILD
         #[clap(short, long)]
         aarch64: bool,
+        /// If set, run a cosmwasm-check validity pass on the built .wasm and report required capabilities
+        #[clap(short, long)]
+        check: bool,
+    },
+    /// Generate JSON Schema for a contract's messages
+    Schema {
+        /// Contract name, if not specified, generate schema for all contracts
+        contract: Option<String>,
+    },
+    /// Show cw2 contract info (contract name & version) for a deployed contract
+    Info {
+        /// Address of the contract to inspect
+        contract_addr: String,
+    },
+    /// Migrate a deployed contract to a new code id, verifying the cw2 version advances
+    Migrate {
+        /// Address of the contract to migrate
+        contract_addr: String,
+        /// Code id to migrate to
+        #[clap(short, long)]
+        code_id: u64,
+        /// Migrate message as JSON
+        migrate_msg: String,
+        /// cw2 version `code_id` reports after migration
+        #[clap(short, long)]
+        new_version: String,
+        /// Allow migrating to a code whose cw2 version is older than the current one
+        #[clap(long)]
+        allow_downgrade: bool,
     },
 }
 
@@ -40,8 +72,22 @@ impl<'a> Module<'a, CWConfig, CWCmd, anyhow::Error> for CWModule {
                 name,
                 target_dir, // TODO: Rremove this
                 version,
-            } => ops::new(ctx, name, version.to_owned(), target_dir.to_owned()),
-            CWCmd::Build { optimize, aarch64 } => ops::build(ctx, optimize, aarch64),
+                e2e,
+            } => ops::new(ctx, name, version.to_owned(), target_dir.to_owned(), e2e),
+            CWCmd::Build {
+                optimize,
+                aarch64,
+                check,
+            } => ops::build(ctx, optimize, aarch64, check),
+            CWCmd::Schema { contract } => ops::schema(ctx, contract),
+            CWCmd::Info { contract_addr } => ops::info(ctx, contract_addr),
+            CWCmd::Migrate {
+                contract_addr,
+                code_id,
+                migrate_msg,
+                new_version,
+                allow_downgrade,
+            } => ops::migrate(ctx, contract_addr, code_id, migrate_msg, new_version, allow_downgrade),
         }
     }
 }
@@ -75,6 +121,7 @@ mod tests {
                 name: "counter-1".to_string(),
                 target_dir: None,
                 version: None,
+                e2e: false,
             },
         )
         .unwrap();
@@ -90,6 +137,7 @@ mod tests {
                 name: "counter-2".to_string(),
                 target_dir: None,
                 version: None,
+                e2e: false,
             },
         )
         .unwrap();
@@ -116,6 +164,7 @@ mod tests {
                 name: "counter-1".to_string(),
                 target_dir: None,
                 version: None,
+                e2e: false,
             },
         )
         .unwrap();
@@ -128,6 +177,7 @@ mod tests {
                 name: "counter-2".to_string(),
                 target_dir: None,
                 version: None,
+                e2e: false,
             },
         )
         .unwrap();
@@ -154,6 +204,7 @@ mod tests {
                 name: "counter-1".to_string(),
                 target_dir: None,
                 version: Some("0.16".into()),
+                e2e: false,
             },
         )
         .unwrap();
@@ -167,6 +218,7 @@ mod tests {
                 name: "counter-2".to_string(),
                 target_dir: None,
                 version: Some("0.16".into()),
+                e2e: false,
             },
         )
         .unwrap();
@@ -195,6 +247,7 @@ mod tests {
                 name: "counter-1".to_string(),
                 target_dir: Some("custom-path".into()),
                 version: None,
+                e2e: false,
             },
         )
         .unwrap();
@@ -207,6 +260,7 @@ mod tests {
                 name: "counter-2".to_string(),
                 target_dir: Some("custom-path".into()),
                 version: None,
+                e2e: false,
             },
         )
         .unwrap();
@@ -216,6 +270,134 @@ mod tests {
         temp.close().unwrap();
     }
 
+    #[test]
+    #[serial]
+    fn generate_schema_for_contract() {
+        let temp = setup();
+
+        CWModule::execute(
+            CWContext {},
+            &CWCmd::New {
+                name: "counter-1".to_string(),
+                target_dir: None,
+                version: None,
+                e2e: false,
+            },
+        )
+        .unwrap();
+        temp.child("contracts/counter-1/schema")
+            .assert(predicate::path::missing());
+
+        CWModule::execute(
+            CWContext {},
+            &CWCmd::Schema {
+                contract: Some("counter-1".to_string()),
+            },
+        )
+        .unwrap();
+        temp.child("contracts/counter-1/schema")
+            .assert(predicate::path::exists());
+
+        temp.close().unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn generate_contract_with_e2e_crate() {
+        let temp = setup();
+
+        CWModule::execute(
+            CWContext {},
+            &CWCmd::New {
+                name: "counter-1".to_string(),
+                target_dir: None,
+                version: None,
+                e2e: true,
+            },
+        )
+        .unwrap();
+        temp.child("contracts/counter-1")
+            .assert(predicate::path::exists());
+        temp.child("e2e").assert(predicate::path::exists());
+
+        let manifest = Manifest::from_path(temp.child("e2e/Cargo.toml").path()).unwrap();
+        assert!(manifest.dependencies.contains_key("cw-multi-test"));
+        assert!(manifest.dependencies.contains_key("counter-1"));
+
+        let integration_test =
+            fs::read_to_string(temp.child("e2e/tests/counter_1.rs").path()).unwrap();
+        assert!(integration_test.contains("instantiate_contract"));
+        assert!(integration_test.contains("execute_contract"));
+        assert!(integration_test.contains("query_wasm_smart"));
+
+        temp.close().unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn generate_e2e_crate_twice_merges_instead_of_overwriting() {
+        let temp = setup();
+
+        for name in ["counter-1", "counter-2"] {
+            CWModule::execute(
+                CWContext {},
+                &CWCmd::New {
+                    name: name.to_string(),
+                    target_dir: None,
+                    version: None,
+                    e2e: true,
+                },
+            )
+            .unwrap();
+        }
+
+        temp.child("e2e/tests/counter_1.rs")
+            .assert(predicate::path::exists());
+        temp.child("e2e/tests/counter_2.rs")
+            .assert(predicate::path::exists());
+
+        let manifest = Manifest::from_path(temp.child("e2e/Cargo.toml").path()).unwrap();
+        assert!(manifest.dependencies.contains_key("counter-1"));
+        assert!(manifest.dependencies.contains_key("counter-2"));
+        assert!(manifest.dependencies.contains_key("cw-multi-test"));
+
+        temp.close().unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn generate_contract_with_e2e_crate_custom_target_dir_and_version() {
+        let temp = setup();
+        env::set_current_dir(&temp).unwrap();
+
+        CWModule::execute(
+            CWContext {},
+            &CWCmd::New {
+                name: "counter-1".to_string(),
+                target_dir: Some("custom-path".into()),
+                version: Some("0.16".into()),
+                e2e: true,
+            },
+        )
+        .unwrap();
+        temp.child("custom-path/counter-1")
+            .assert(predicate::path::exists());
+        temp.child("e2e").assert(predicate::path::exists());
+
+        assert_version(Path::new("e2e/Cargo.toml"), "0.16");
+
+        let manifest = Manifest::from_path(temp.child("e2e/Cargo.toml").path()).unwrap();
+        if let Dependency::Detailed(DependencyDetail { path: Some(path), .. }) =
+            manifest.dependencies.get("counter-1").unwrap()
+        {
+            assert!(Path::new(path).ends_with("custom-path/counter-1"));
+        } else {
+            panic!("expected a detailed path dependency for `counter-1`");
+        }
+
+        temp.close().unwrap();
+    }
+
     fn setup() -> TempDir {
         let temp = assert_fs::TempDir::new().unwrap();
         env::set_current_dir(&temp).unwrap();
@@ -0,0 +1,752 @@
+use super::config::CWConfig;
+use crate::framework::Context;
+use anyhow::{bail, Context as _, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use semver::Version;
+use serde::Deserialize;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use wasmparser::{Operator, Parser, Payload};
+
+const CONTRACTS_DIR: &str = "contracts";
+const SCHEMA_DIR: &str = "schema";
+const ARTIFACTS_DIR: &str = "artifacts";
+const WASM_TARGET: &str = "wasm32-unknown-unknown";
+const PROJECT_MARKER: &str = "Protostar.toml";
+const DEFAULT_TEMPLATE_REPO: &str = "https://github.com/CosmWasm/cw-template";
+const DEFAULT_TEMPLATE_BRANCH: &str = "main";
+
+/// Entry points every CosmWasm contract must export to be instantiable on-chain.
+const REQUIRED_EXPORTS: &[&str] = &["instantiate", "query"];
+/// Host modules a contract may import from; cosmwasm-vm exposes every host function under `env`.
+const ALLOWED_IMPORT_MODULES: &[&str] = &["env"];
+/// Matches cosmwasm-vm's default `MEMORY_LIMIT` of 32 MiB (512 * 64KiB pages).
+const MAX_MEMORY_PAGES: u64 = 512;
+
+/// Hex-encoded `contract_info`, the well-known cw2 state key.
+const CONTRACT_INFO_KEY_HEX: &str = "636f6e74726163745f696e666f";
+const DEFAULT_LCD_URL: &str = "http://localhost:1317";
+
+/// `cosmwasm-std` version to pin the scaffolded `e2e` crate to when `--version` isn't given.
+const DEFAULT_COSMWASM_STD_VERSION: &str = "1.5";
+
+/// Create new CosmWasm contract from boilerplate. If `e2e` is set, also scaffold a
+/// separate `e2e` integration-test crate (depending on `cw-multi-test`) alongside the
+/// contract, rather than mixing host-side tests into the contract's own `#[cfg(test)]`.
+pub fn new<'a, Ctx: Context<'a, CWConfig>>(
+    _ctx: Ctx,
+    name: &str,
+    version: Option<String>,
+    target_dir: Option<PathBuf>,
+    e2e: &bool,
+) -> Result<()> {
+    let project_root = find_project_root()?;
+    let contracts_dir = target_dir.unwrap_or_else(|| project_root.join(CONTRACTS_DIR));
+    fs::create_dir_all(&contracts_dir)?;
+
+    generate_from_template(name, version.as_deref(), &contracts_dir)?;
+
+    if *e2e {
+        scaffold_e2e_crate(&project_root, name, &contracts_dir, version.as_deref())?;
+    }
+
+    Ok(())
+}
+
+fn find_project_root() -> Result<PathBuf> {
+    let mut dir = env::current_dir()?;
+    loop {
+        if dir.join(PROJECT_MARKER).exists() {
+            return Ok(dir);
+        }
+        if !dir.pop() {
+            bail!("could not find `{PROJECT_MARKER}`; run this command inside a beaker project");
+        }
+    }
+}
+
+fn generate_from_template(name: &str, version: Option<&str>, contracts_dir: &Path) -> Result<()> {
+    let branch = version.unwrap_or(DEFAULT_TEMPLATE_BRANCH);
+
+    let status = Command::new("cargo")
+        .args([
+            "generate",
+            "--git",
+            DEFAULT_TEMPLATE_REPO,
+            "--branch",
+            branch,
+            "--name",
+            name,
+            "--destination",
+        ])
+        .arg(contracts_dir)
+        .arg("--silent")
+        .status()
+        .context("unable to run `cargo generate`; is cargo-generate installed?")?;
+
+    if !status.success() {
+        bail!("failed to generate contract `{name}` from template");
+    }
+
+    Ok(())
+}
+
+/// Scaffold (or extend, for projects with multiple contracts) a standalone `e2e` crate at `project_root/e2e`.
+fn scaffold_e2e_crate(
+    project_root: &Path,
+    contract_name: &str,
+    contracts_dir: &Path,
+    version: Option<&str>,
+) -> Result<()> {
+    let e2e_dir = project_root.join("e2e");
+    let tests_dir = e2e_dir.join("tests");
+    fs::create_dir_all(&tests_dir)?;
+
+    let contract_dir = contracts_dir.join(contract_name);
+    let contract_rel_path = relative_path(&e2e_dir, &contract_dir).with_context(|| {
+        format!("unable to resolve path to generated contract `{}`", contract_dir.display())
+    })?;
+    let cosmwasm_std_version = version.unwrap_or(DEFAULT_COSMWASM_STD_VERSION);
+
+    upsert_e2e_cargo_toml(&e2e_dir.join("Cargo.toml"), contract_name, &contract_rel_path, cosmwasm_std_version)
+        .context("unable to update e2e/Cargo.toml")?;
+
+    let contract_ident = contract_name.replace('-', "_");
+    fs::write(tests_dir.join(format!("{contract_ident}.rs")), e2e_integration_test(contract_name))
+        .with_context(|| format!("unable to write e2e/tests/{contract_ident}.rs"))?;
+
+    Ok(())
+}
+
+/// Compute `to`'s path relative to `from`, so generated manifests stay portable across
+/// clones/moves of the project instead of baking in an absolute, machine-specific path.
+fn relative_path(from: &Path, to: &Path) -> Result<PathBuf> {
+    let from = fs::canonicalize(from)
+        .with_context(|| format!("unable to resolve path `{}`", from.display()))?;
+    let to = fs::canonicalize(to).with_context(|| format!("unable to resolve path `{}`", to.display()))?;
+
+    let common = from
+        .components()
+        .zip(to.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut relative = PathBuf::new();
+    for _ in common..from.components().count() {
+        relative.push("..");
+    }
+    relative.extend(to.components().skip(common));
+
+    Ok(relative)
+}
+
+/// Insert or update the `e2e` crate's manifest with a dependency on `contract_name`, without disturbing existing entries.
+fn upsert_e2e_cargo_toml(
+    path: &Path,
+    contract_name: &str,
+    contract_rel_path: &Path,
+    cosmwasm_std_version: &str,
+) -> Result<()> {
+    let mut manifest: toml::Value = if path.exists() {
+        fs::read_to_string(path)
+            .with_context(|| format!("unable to read `{}`", path.display()))?
+            .parse()
+            .with_context(|| format!("unable to parse `{}`", path.display()))?
+    } else {
+        toml::Value::Table(toml::value::Table::new())
+    };
+
+    let root = manifest.as_table_mut().context("expected a TOML table at the manifest root")?;
+
+    root.entry("package").or_insert_with(|| {
+        toml::Value::Table(toml::Table::from_iter([
+            ("name".to_string(), toml::Value::String("e2e".to_string())),
+            ("version".to_string(), toml::Value::String("0.1.0".to_string())),
+            ("edition".to_string(), toml::Value::String("2021".to_string())),
+            ("publish".to_string(), toml::Value::Boolean(false)),
+        ]))
+    });
+
+    let dependencies = root
+        .entry("dependencies")
+        .or_insert_with(|| toml::Value::Table(toml::value::Table::new()))
+        .as_table_mut()
+        .context("expected `dependencies` to be a TOML table")?;
+
+    dependencies.insert(
+        contract_name.to_string(),
+        toml::Value::Table(toml::Table::from_iter([(
+            "path".to_string(),
+            toml::Value::String(contract_rel_path.display().to_string()),
+        )])),
+    );
+    dependencies.entry("cosmwasm-std").or_insert_with(|| {
+        toml::Value::Table(toml::Table::from_iter([(
+            "version".to_string(),
+            toml::Value::String(cosmwasm_std_version.to_string()),
+        )]))
+    });
+    dependencies
+        .entry("cw-multi-test")
+        .or_insert_with(|| toml::Value::String("0.20".to_string()));
+
+    fs::write(path, toml::to_string_pretty(&manifest)?)?;
+
+    Ok(())
+}
+
+fn e2e_integration_test(contract_name: &str) -> String {
+    let contract_ident = contract_name.replace('-', "_");
+    format!(
+        r#"use cosmwasm_std::{{Addr, Empty}};
+use cw_multi_test::{{App, ContractWrapper, Executor}};
+use {contract_ident}::msg::{{CountResponse, ExecuteMsg, InstantiateMsg, QueryMsg}};
+
+fn contract() -> Box<dyn cw_multi_test::Contract<Empty>> {{
+    Box::new(ContractWrapper::new(
+        {contract_ident}::contract::execute,
+        {contract_ident}::contract::instantiate,
+        {contract_ident}::contract::query,
+    ))
+}}
+
+#[test]
+fn instantiate_execute_and_query() {{
+    let mut app = App::default();
+    let owner = Addr::unchecked("owner");
+
+    let code_id = app.store_code(contract());
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            owner.clone(),
+            &InstantiateMsg {{ count: 0 }},
+            &[],
+            "{contract_name}",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(owner, contract_addr.clone(), &ExecuteMsg::Increment {{}}, &[])
+        .unwrap();
+
+    let resp: CountResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::GetCount {{}})
+        .unwrap();
+    assert_eq!(resp.count, 1);
+}}
+"#
+    )
+}
+
+/// Generate JSON Schema for a contract's `InstantiateMsg`/`ExecuteMsg`/`QueryMsg`
+/// (and query responses) by running the contract's `examples/schema.rs`,
+/// mirroring the convention used across CosmWasm contracts.
+pub fn schema<'a, Ctx: Context<'a, CWConfig>>(_ctx: Ctx, contract: &Option<String>) -> Result<()> {
+    for dir in contract_dirs(contract)? {
+        run_schema_example(&dir)?;
+
+        let schema_dir = dir.join(SCHEMA_DIR);
+        let mut paths: Vec<PathBuf> = fs::read_dir(&schema_dir)
+            .with_context(|| format!("unable to read schema dir `{}`", schema_dir.display()))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().map(|ext| ext == "json").unwrap_or(false))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            println!("generated {}", path.display());
+        }
+    }
+
+    Ok(())
+}
+
+fn contract_dirs(contract: &Option<String>) -> Result<Vec<PathBuf>> {
+    let contracts_dir = Path::new(CONTRACTS_DIR);
+
+    match contract {
+        Some(name) => {
+            let dir = contracts_dir.join(name);
+            if !dir.exists() {
+                bail!("contract `{name}` not found in `{CONTRACTS_DIR}`");
+            }
+            Ok(vec![dir])
+        }
+        None => {
+            let mut dirs = vec![];
+            for entry in fs::read_dir(contracts_dir)
+                .with_context(|| format!("unable to read `{CONTRACTS_DIR}` dir"))?
+            {
+                let entry = entry?;
+                if entry.file_type()?.is_dir() {
+                    dirs.push(entry.path());
+                }
+            }
+            dirs.sort();
+            Ok(dirs)
+        }
+    }
+}
+
+fn run_schema_example(contract_dir: &Path) -> Result<()> {
+    let manifest_path = contract_dir.join("Cargo.toml");
+    let status = Command::new("cargo")
+        .args(["run", "--example", "schema", "--manifest-path"])
+        .arg(&manifest_path)
+        .current_dir(contract_dir)
+        .status()
+        .with_context(|| format!("unable to run schema example in `{}`", contract_dir.display()))?;
+
+    if !status.success() {
+        bail!(
+            "schema generation failed for contract at `{}`",
+            contract_dir.display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Build .wasm for storing contract code on the blockchain. If `optimize` is set, the
+/// artifact is passed through the CosmWasm rust-optimizer after the raw build. If `check`
+/// is set, the resulting .wasm is validated for on-chain deployability and the set of
+/// capabilities it requires is printed.
+pub fn build<'a, Ctx: Context<'a, CWConfig>>(
+    _ctx: Ctx,
+    optimize: &bool,
+    aarch64: &bool,
+    check: &bool,
+) -> Result<()> {
+    for dir in contract_dirs(&None)? {
+        cargo_build(&dir)?;
+
+        let wasm_path = if *optimize {
+            optimize_wasm(&dir, *aarch64)?
+        } else {
+            raw_wasm_path(&dir)?
+        };
+
+        if *check {
+            let bytes = fs::read(&wasm_path)
+                .with_context(|| format!("unable to read `{}`", wasm_path.display()))?;
+            let report = validate_wasm_module(&bytes)
+                .with_context(|| format!("`{}` is not a valid CosmWasm contract", wasm_path.display()))?;
+
+            if report.capabilities.is_empty() {
+                println!("{}: no special capabilities required", wasm_path.display());
+            } else {
+                println!(
+                    "{}: requires capabilities [{}]",
+                    wasm_path.display(),
+                    report.capabilities.join(", ")
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn cargo_build(contract_dir: &Path) -> Result<()> {
+    let manifest_path = contract_dir.join("Cargo.toml");
+    let status = Command::new("cargo")
+        .args(["build", "--release", "--lib", "--target", WASM_TARGET, "--manifest-path"])
+        .arg(&manifest_path)
+        .status()
+        .with_context(|| format!("unable to build contract at `{}`", contract_dir.display()))?;
+
+    if !status.success() {
+        bail!("build failed for contract at `{}`", contract_dir.display());
+    }
+
+    Ok(())
+}
+
+/// The crate name as it appears in the built `.wasm` filename, derived from the contract's
+/// directory name (cargo replaces `-` with `_` for the library artifact).
+fn crate_name(contract_dir: &Path) -> Result<String> {
+    let dir_name = contract_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .with_context(|| format!("unable to determine crate name for `{}`", contract_dir.display()))?;
+    Ok(dir_name.replace('-', "_"))
+}
+
+fn raw_wasm_path(contract_dir: &Path) -> Result<PathBuf> {
+    let name = crate_name(contract_dir)?;
+    Ok(contract_dir
+        .join("target")
+        .join(WASM_TARGET)
+        .join("release")
+        .join(format!("{name}.wasm")))
+}
+
+fn optimize_wasm(contract_dir: &Path, aarch64: bool) -> Result<PathBuf> {
+    let image = if aarch64 {
+        "cosmwasm/rust-optimizer-arm64:0.15.0"
+    } else {
+        "cosmwasm/rust-optimizer:0.15.0"
+    };
+
+    let status = Command::new("docker")
+        .args(["run", "--rm", "-v"])
+        .arg(format!("{}:/code", contract_dir.display()))
+        .arg("--mount")
+        .arg("type=volume,source=registry_cache,target=/usr/local/cargo/registry")
+        .arg(image)
+        .status()
+        .with_context(|| format!("unable to run optimizer for `{}`", contract_dir.display()))?;
+
+    if !status.success() {
+        bail!("optimization failed for contract at `{}`", contract_dir.display());
+    }
+
+    let name = crate_name(contract_dir)?;
+    Ok(contract_dir.join(ARTIFACTS_DIR).join(format!("{name}.wasm")))
+}
+
+#[derive(Debug)]
+struct WasmReport {
+    capabilities: Vec<String>,
+}
+
+fn validate_wasm_module(bytes: &[u8]) -> Result<WasmReport> {
+    let mut exports = vec![];
+    let mut imports = vec![];
+    let mut memory_pages = None;
+    let mut has_float_op = false;
+
+    for payload in Parser::new(0).parse_all(bytes) {
+        match payload? {
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    let import = import?;
+                    imports.push((import.module.to_string(), import.name.to_string()));
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    exports.push(export?.name.to_string());
+                }
+            }
+            Payload::MemorySection(reader) => {
+                for memory in reader {
+                    memory_pages = Some(memory?.initial);
+                }
+            }
+            Payload::CodeSectionEntry(body) => {
+                for op in body.get_operators_reader()?.into_iter() {
+                    if is_float_operator(&op?) {
+                        has_float_op = true;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for required in REQUIRED_EXPORTS {
+        if !exports.iter().any(|export| export == required) {
+            bail!("missing required export `{required}`");
+        }
+    }
+
+    for (module, name) in &imports {
+        if !ALLOWED_IMPORT_MODULES.contains(&module.as_str()) {
+            bail!("imports `{name}` from non-whitelisted module `{module}`");
+        }
+    }
+
+    if let Some(pages) = memory_pages {
+        if pages > MAX_MEMORY_PAGES {
+            bail!("requests {pages} pages of memory, exceeding the {MAX_MEMORY_PAGES} page bound");
+        }
+    }
+
+    if has_float_op {
+        bail!("contains floating-point operations, which are unsupported on-chain");
+    }
+
+    // `db_next` is cosmwasm-vm's iterator-advance host function; a contract importing it
+    // needs the chain to have the `iterator` capability enabled. Other capabilities (e.g.
+    // `stargate`, `staking`) aren't distinguishable from a contract's import list alone --
+    // those queries are all routed through the same generic `query_chain` host function --
+    // so we don't attempt to infer them here.
+    let mut capabilities: Vec<String> = vec![];
+    if imports.iter().any(|(_, name)| name == "db_next") {
+        capabilities.push("iterator".to_string());
+    }
+    capabilities.sort();
+
+    Ok(WasmReport { capabilities })
+}
+
+/// Matches float-producing/consuming operators by checking for `F32`/`F64` in the `Debug`
+/// output, rather than hand-maintaining an exhaustive match over `Operator`'s many variants.
+fn is_float_operator(op: &Operator) -> bool {
+    let name = format!("{op:?}");
+    name.contains("F32") || name.contains("F64")
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+struct ContractInfo {
+    contract: String,
+    version: String,
+}
+
+/// Show cw2 `contract_info` (contract name & version) for a deployed contract, read via a
+/// raw-state query against the well-known `contract_info` key.
+pub fn info<'a, Ctx: Context<'a, CWConfig>>(_ctx: Ctx, contract_addr: &str) -> Result<()> {
+    let info = decode_contract_info(&query_raw_state(contract_addr, CONTRACT_INFO_KEY_HEX)?)?;
+    println!("{contract_addr}: {} v{}", info.contract, info.version);
+    Ok(())
+}
+
+/// Migrate a deployed contract to `code_id`, refusing by default if `new_version` (the cw2
+/// version `code_id` is expected to report) is older than the contract's current one, and
+/// confirming afterwards that the contract actually reports `new_version`.
+pub fn migrate<'a, Ctx: Context<'a, CWConfig>>(
+    _ctx: Ctx,
+    contract_addr: &str,
+    code_id: &u64,
+    migrate_msg: &str,
+    new_version: &str,
+    allow_downgrade: &bool,
+) -> Result<()> {
+    let before = decode_contract_info(&query_raw_state(contract_addr, CONTRACT_INFO_KEY_HEX)?)?;
+    ensure_no_downgrade(&before.version, new_version, *allow_downgrade)?;
+
+    run_migrate_tx(contract_addr, *code_id, migrate_msg)?;
+
+    let after = decode_contract_info(&query_raw_state(contract_addr, CONTRACT_INFO_KEY_HEX)?)?;
+    if after.version != new_version {
+        bail!(
+            "expected `{contract_addr}` to report cw2 version `{new_version}` after migration, \
+             but it reports `{}`",
+            after.version
+        );
+    }
+
+    println!(
+        "migrated {contract_addr} from {} v{} to {} v{}",
+        before.contract, before.version, after.contract, after.version
+    );
+
+    Ok(())
+}
+
+fn decode_contract_info(raw: &[u8]) -> Result<ContractInfo> {
+    serde_json::from_slice(raw).context("unable to decode cw2 contract_info")
+}
+
+fn ensure_no_downgrade(current: &str, new: &str, allow_downgrade: bool) -> Result<()> {
+    if allow_downgrade {
+        return Ok(());
+    }
+
+    let current_version =
+        Version::parse(current).with_context(|| format!("invalid current version `{current}`"))?;
+    let new_version = Version::parse(new).with_context(|| format!("invalid new version `{new}`"))?;
+
+    if new_version < current_version {
+        bail!(
+            "refusing to downgrade contract from v{current_version} to v{new_version}; \
+             pass --allow-downgrade to override"
+        );
+    }
+
+    Ok(())
+}
+
+fn query_raw_state(contract_addr: &str, key_hex: &str) -> Result<Vec<u8>> {
+    #[derive(Deserialize)]
+    struct RawQueryResponse {
+        data: String,
+    }
+
+    let lcd_url = env::var("BEAKER_LCD_URL").unwrap_or_else(|_| DEFAULT_LCD_URL.to_string());
+    let url = format!("{lcd_url}/cosmwasm/wasm/v1/contract/{contract_addr}/raw/{key_hex}?encoding=hex");
+
+    let response: RawQueryResponse = reqwest::blocking::get(&url)
+        .with_context(|| format!("unable to query `{url}`"))?
+        .json()
+        .with_context(|| format!("unable to parse raw query response from `{url}`"))?;
+
+    STANDARD
+        .decode(response.data)
+        .context("unable to base64-decode raw query response")
+}
+
+fn run_migrate_tx(contract_addr: &str, code_id: u64, migrate_msg: &str) -> Result<()> {
+    let chain_binary = env::var("BEAKER_CHAIN_BINARY").unwrap_or_else(|_| "wasmd".to_string());
+    let from = env::var("BEAKER_SIGNER_KEY")
+        .context("BEAKER_SIGNER_KEY must be set to submit a migrate transaction")?;
+    let chain_id = env::var("BEAKER_CHAIN_ID")
+        .context("BEAKER_CHAIN_ID must be set to submit a migrate transaction")?;
+
+    let status = Command::new(&chain_binary)
+        .args([
+            "tx",
+            "wasm",
+            "migrate",
+            contract_addr,
+            &code_id.to_string(),
+            migrate_msg,
+            "--from",
+            &from,
+            "--chain-id",
+            &chain_id,
+            "--broadcast-mode",
+            "block",
+            "-y",
+        ])
+        .status()
+        .with_context(|| format!("unable to run `{chain_binary} tx wasm migrate`"))?;
+
+    if !status.success() {
+        bail!("migrate transaction failed for contract `{contract_addr}`");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_wasm_missing_required_export() {
+        let bytes = wat::parse_str(
+            r#"
+            (module
+                (memory 1)
+                (func (export "query") (param i32 i32) (result i32) i32.const 0)
+            )
+            "#,
+        )
+        .unwrap();
+
+        let err = validate_wasm_module(&bytes).unwrap_err();
+        assert!(err.to_string().contains("missing required export `instantiate`"));
+    }
+
+    #[test]
+    fn rejects_wasm_with_float_ops() {
+        let bytes = wat::parse_str(
+            r#"
+            (module
+                (memory 1)
+                (func (export "instantiate") (param i32 i32 i32) (result i32) i32.const 0)
+                (func (export "query") (param i32 i32) (result i32) i32.const 0)
+                (func $uses_float (result f32) f32.const 1.0 f32.const 2.0 f32.add)
+            )
+            "#,
+        )
+        .unwrap();
+
+        let err = validate_wasm_module(&bytes).unwrap_err();
+        assert!(err.to_string().contains("floating-point"));
+    }
+
+    #[test]
+    fn rejects_wasm_with_float_to_int_conversion() {
+        let bytes = wat::parse_str(
+            r#"
+            (module
+                (memory 1)
+                (func (export "instantiate") (param i32 i32 i32) (result i32) i32.const 0)
+                (func (export "query") (param i32 i32) (result i32) i32.const 0)
+                (func $uses_trunc (param f32) (result i32) local.get 0 i32.trunc_f32_s)
+            )
+            "#,
+        )
+        .unwrap();
+
+        let err = validate_wasm_module(&bytes).unwrap_err();
+        assert!(err.to_string().contains("floating-point"));
+    }
+
+    #[test]
+    fn rejects_wasm_with_disallowed_import() {
+        let bytes = wat::parse_str(
+            r#"
+            (module
+                (import "wasi_snapshot_preview1" "fd_write" (func (param i32 i32 i32 i32) (result i32)))
+                (memory 1)
+                (func (export "instantiate") (param i32 i32 i32) (result i32) i32.const 0)
+                (func (export "query") (param i32 i32) (result i32) i32.const 0)
+            )
+            "#,
+        )
+        .unwrap();
+
+        let err = validate_wasm_module(&bytes).unwrap_err();
+        assert!(err.to_string().contains("non-whitelisted module"));
+    }
+
+    #[test]
+    fn reports_iterator_capability_for_valid_wasm() {
+        let bytes = wat::parse_str(
+            r#"
+            (module
+                (import "env" "db_next" (func (param i32) (result i32)))
+                (memory 1)
+                (func (export "instantiate") (param i32 i32 i32) (result i32) i32.const 0)
+                (func (export "query") (param i32 i32) (result i32) i32.const 0)
+            )
+            "#,
+        )
+        .unwrap();
+
+        let report = validate_wasm_module(&bytes).unwrap();
+        assert_eq!(report.capabilities, vec!["iterator".to_string()]);
+    }
+
+    #[test]
+    fn reports_no_capabilities_without_iterator_import() {
+        let bytes = wat::parse_str(
+            r#"
+            (module
+                (import "env" "query_chain" (func (param i32) (result i32)))
+                (memory 1)
+                (func (export "instantiate") (param i32 i32 i32) (result i32) i32.const 0)
+                (func (export "query") (param i32 i32) (result i32) i32.const 0)
+            )
+            "#,
+        )
+        .unwrap();
+
+        let report = validate_wasm_module(&bytes).unwrap();
+        assert!(report.capabilities.is_empty());
+    }
+
+    #[test]
+    fn decodes_cw2_contract_info() {
+        let raw = br#"{"contract":"crates.io:counter","version":"0.2.0"}"#;
+        let info = decode_contract_info(raw).unwrap();
+        assert_eq!(info.contract, "crates.io:counter");
+        assert_eq!(info.version, "0.2.0");
+    }
+
+    #[test]
+    fn refuses_downgrade_by_default() {
+        let err = ensure_no_downgrade("0.2.0", "0.1.0", false).unwrap_err();
+        assert!(err.to_string().contains("refusing to downgrade"));
+    }
+
+    #[test]
+    fn allows_downgrade_when_flag_set() {
+        ensure_no_downgrade("0.2.0", "0.1.0", true).unwrap();
+    }
+
+    #[test]
+    fn allows_upgrade() {
+        ensure_no_downgrade("0.1.0", "0.2.0", false).unwrap();
+    }
+}
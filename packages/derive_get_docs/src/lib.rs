@@ -0,0 +1,311 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+
+const LEAF_TYPES: &[&str] = &[
+    "String", "str", "bool", "char", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16",
+    "i32", "i64", "i128", "isize", "f32", "f64",
+    // cosmwasm-std wrapper types: these are foreign to this crate, so we can't recurse into
+    // them via `GetDocs` -- treat them as opaque leaves and let `get_docs`'s schema fallback
+    // (untyped-struct -> "string") describe them instead.
+    "Uint64", "Uint128", "Uint256", "Decimal", "Decimal256", "Addr", "Coin", "Binary",
+    "HexBinary", "Timestamp",
+];
+
+const CONTAINER_IDENTS: &[&str] = &["HashMap", "BTreeMap", "Vec", "VecDeque", "Option", "BTreeSet", "HashSet"];
+
+#[proc_macro_derive(GetDocs, attributes(serde))]
+pub fn derive_get_docs(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let rename_all = serde_rename_all(&input.attrs);
+    let docs = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .map(|field| field_doc(field, rename_all))
+                .collect::<Vec<_>>(),
+            _ => panic!("GetDocs can only be derived for structs with named fields"),
+        },
+        // CosmWasm message types (`ExecuteMsg`, `QueryMsg`, ...) are enums, so each variant is
+        // documented as its own entry -- struct variants recurse into their named fields the
+        // same way a struct's fields do, tuple/unit variants get no nested fields (a tuple
+        // variant's type string lists its field types, a unit variant's is empty).
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| variant_doc(variant, rename_all))
+            .collect::<Vec<_>>(),
+        _ => panic!("GetDocs can only be derived for structs and enums"),
+    };
+
+    let expanded = quote! {
+        impl get_docs::GetDocs for #name {
+            fn get_struct_docs() -> Vec<get_docs::StructDoc> {
+                vec![#(#docs),*]
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// The key a CosmWasm message's wire JSON uses for this field, honoring `#[serde(rename)]` /
+/// the container's `#[serde(rename_all)]` -- these types are conventionally annotated with
+/// one or the other, so documenting the raw Rust identifier instead would describe a field
+/// name that doesn't actually appear on the wire.
+fn field_doc(field: &syn::Field, rename_all: Option<RenameRule>) -> proc_macro2::TokenStream {
+    let ident = field.ident.as_ref().unwrap().to_string();
+    let field_name = serde_rename(&field.attrs)
+        .unwrap_or_else(|| rename_all.map_or_else(|| ident.clone(), |rule| rule.apply_to_field(&ident)));
+    let ty = &field.ty;
+    let ty_str = quote!(#ty).to_string().replace(" ,", ",");
+    let doc_lines = extract_doc_lines(&field.attrs);
+
+    let nested_docs = match nested_type(ty) {
+        Some(inner) => quote! { <#inner as get_docs::GetDocs>::get_struct_docs() },
+        None => quote! { ::std::vec::Vec::new() },
+    };
+
+    quote! {
+        get_docs::StructDoc::new(
+            #field_name.to_string(),
+            #ty_str.to_string(),
+            vec![#(#doc_lines.to_string()),*],
+            #nested_docs,
+        )
+    }
+}
+
+fn variant_doc(variant: &syn::Variant, rename_all: Option<RenameRule>) -> proc_macro2::TokenStream {
+    let ident = variant.ident.to_string();
+    let variant_name = serde_rename(&variant.attrs)
+        .unwrap_or_else(|| rename_all.map_or_else(|| ident.clone(), |rule| rule.apply_to_variant(&ident)));
+    let doc_lines = extract_doc_lines(&variant.attrs);
+
+    let (ty_str, nested_docs) = match &variant.fields {
+        Fields::Named(fields) => {
+            // `render_section` titles a variant's nested field table with its `ty`, so a
+            // struct variant needs its own name there rather than an empty string. A
+            // variant's own `#[serde(rename_all)]` (not the container's) governs its fields.
+            let field_rename_all = serde_rename_all(&variant.attrs);
+            let field_docs = fields.named.iter().map(|field| field_doc(field, field_rename_all));
+            (variant_name.clone(), quote! { vec![#(#field_docs),*] })
+        }
+        Fields::Unnamed(fields) => {
+            let ty_str = fields
+                .unnamed
+                .iter()
+                .map(|field| {
+                    let ty = &field.ty;
+                    quote!(#ty).to_string()
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            (ty_str, quote! { ::std::vec::Vec::new() })
+        }
+        Fields::Unit => (String::new(), quote! { ::std::vec::Vec::new() }),
+    };
+
+    quote! {
+        get_docs::StructDoc::new(
+            #variant_name.to_string(),
+            #ty_str.to_string(),
+            vec![#(#doc_lines.to_string()),*],
+            #nested_docs,
+        )
+    }
+}
+
+fn extract_doc_lines(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| {
+            let syn::Meta::NameValue(meta) = &attr.meta else {
+                return None;
+            };
+            let syn::Expr::Lit(expr_lit) = &meta.value else {
+                return None;
+            };
+            let syn::Lit::Str(lit) = &expr_lit.lit else {
+                return None;
+            };
+            Some(lit.value().trim().to_string())
+        })
+        .collect()
+}
+
+/// A `#[serde(rename = "...")]` override, if present.
+fn serde_rename(attrs: &[syn::Attribute]) -> Option<String> {
+    serde_metas(attrs)
+        .into_iter()
+        .find(|meta| meta.path().is_ident("rename"))
+        .and_then(|meta| meta_str_value(&meta))
+}
+
+/// The container's `#[serde(rename_all = "...")]` casing rule, if present.
+fn serde_rename_all(attrs: &[syn::Attribute]) -> Option<RenameRule> {
+    serde_metas(attrs)
+        .into_iter()
+        .find(|meta| meta.path().is_ident("rename_all"))
+        .and_then(|meta| meta_str_value(&meta))
+        .and_then(|rule| RenameRule::from_str(&rule))
+}
+
+fn serde_metas(attrs: &[syn::Attribute]) -> Vec<syn::Meta> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .flat_map(|attr| {
+            attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+                .map(|metas| metas.into_iter().collect::<Vec<_>>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn meta_str_value(meta: &syn::Meta) -> Option<String> {
+    let syn::Meta::NameValue(meta) = meta else {
+        return None;
+    };
+    let syn::Expr::Lit(expr_lit) = &meta.value else {
+        return None;
+    };
+    let syn::Lit::Str(lit) = &expr_lit.lit else {
+        return None;
+    };
+    Some(lit.value())
+}
+
+/// serde's `rename_all` casing rules. Field idents are conventionally written snake_case and
+/// variant idents PascalCase, so each `apply_to_*` converts from that Rust-side baseline to
+/// the target casing, mirroring `serde_derive`'s own `RenameRule`.
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_str(s: &str) -> Option<Self> {
+        Some(match s {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => return None,
+        })
+    }
+
+    fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_string(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => snake_to_camel(field, true),
+            Self::Camel => snake_to_camel(field, false),
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+
+    fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_string(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            Self::Snake => camel_to_snake(variant),
+            Self::ScreamingSnake => camel_to_snake(variant).to_ascii_uppercase(),
+            Self::Kebab => camel_to_snake(variant).replace('_', "-"),
+            Self::ScreamingKebab => camel_to_snake(variant).to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
+
+fn snake_to_camel(field: &str, capitalize_first: bool) -> String {
+    let mut out = String::new();
+    let mut capitalize = capitalize_first;
+    for ch in field.chars() {
+        if ch == '_' {
+            capitalize = true;
+        } else if capitalize {
+            out.extend(ch.to_uppercase());
+            capitalize = false;
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+fn camel_to_snake(variant: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in variant.char_indices() {
+        if i > 0 && ch.is_uppercase() {
+            snake.push('_');
+        }
+        snake.extend(ch.to_lowercase());
+    }
+    snake
+}
+
+/// The type to recurse into for a field, if it isn't a primitive leaf. Unwraps nested
+/// `HashMap`/`BTreeMap` (taking the value type), `Vec`/`Option`/etc (taking the item type)
+/// all the way down -- e.g. `Option<Vec<Config>>` unwraps through both containers to
+/// `Config` -- before checking whether what's left is a known primitive.
+fn nested_type(ty: &Type) -> Option<Type> {
+    let mut inner = ty.clone();
+    while let Some(next) = container_inner_type(&inner) {
+        inner = next;
+    }
+    let ident = leaf_ident(&inner)?;
+    if LEAF_TYPES.contains(&ident.as_str()) {
+        None
+    } else {
+        Some(inner)
+    }
+}
+
+fn container_inner_type(ty: &Type) -> Option<Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let ident = segment.ident.to_string();
+    if !CONTAINER_IDENTS.contains(&ident.as_str()) {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().rev().find_map(|arg| match arg {
+        GenericArgument::Type(t) => Some(t.clone()),
+        _ => None,
+    })
+}
+
+fn leaf_ident(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(type_path) => type_path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,129 @@
+use derive_get_docs::GetDocs;
+use get_docs::{GetDocs, StructDoc};
+use serde_json::json;
+
+#[test]
+fn test_enum_with_empty_and_non_empty_struct_variants() {
+    #[derive(GetDocs)]
+    #[allow(dead_code)]
+    enum ExecuteMsg {
+        /// Reset the counter to zero
+        Reset {},
+        /// Increment the counter by `amount`
+        Increment {
+            /// How much to add to the counter
+            amount: u64,
+        },
+    }
+
+    assert_eq!(
+        ExecuteMsg::get_struct_docs(),
+        vec![
+            StructDoc::new(
+                "Reset".to_string(),
+                "Reset".to_string(),
+                vec!["Reset the counter to zero".to_string()],
+                vec![]
+            ),
+            StructDoc::new(
+                "Increment".to_string(),
+                "Increment".to_string(),
+                vec!["Increment the counter by `amount`".to_string()],
+                vec![StructDoc::new(
+                    "amount".to_string(),
+                    "u64".to_string(),
+                    vec!["How much to add to the counter".to_string()],
+                    vec![]
+                )]
+            ),
+        ]
+    );
+
+    assert_eq!(
+        ExecuteMsg::get_json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "Reset": { "type": "string", "description": "Reset the counter to zero" },
+                "Increment": {
+                    "type": "object",
+                    "description": "Increment the counter by `amount`",
+                    "properties": {
+                        "amount": {
+                            "type": "integer",
+                            "description": "How much to add to the counter"
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    assert_eq!(
+        ExecuteMsg::render_markdown(),
+        "\
+## ExecuteMsg
+
+| Field | Type | Description |
+| --- | --- | --- |
+| Reset | Reset | Reset the counter to zero |
+| Increment | Increment | Increment the counter by `amount` |
+
+### Increment
+
+| Field | Type | Description |
+| --- | --- | --- |
+| amount | u64 | How much to add to the counter |
+"
+    );
+}
+
+#[test]
+fn test_cosmwasm_std_wrapper_types_are_treated_as_leaves() {
+    // Stand-ins for the real `cosmwasm_std` types: the derive matches on type name alone, so
+    // these don't need to (and, to keep this crate free of a cosmwasm-std dev-dependency,
+    // don't) implement `GetDocs` -- if the derive tried to recurse into them as it would for
+    // an unrecognized struct, this test wouldn't compile.
+    #[allow(dead_code)]
+    struct Addr(String);
+    #[allow(dead_code)]
+    struct Uint128(u128);
+
+    #[derive(GetDocs)]
+    #[allow(dead_code)]
+    struct Config {
+        /// Contract admin address
+        admin: Addr,
+        /// Funds required for the action
+        amount: Uint128,
+    }
+
+    assert_eq!(
+        Config::get_struct_docs(),
+        vec![
+            StructDoc::new(
+                "admin".to_string(),
+                "Addr".to_string(),
+                vec!["Contract admin address".to_string()],
+                vec![]
+            ),
+            StructDoc::new(
+                "amount".to_string(),
+                "Uint128".to_string(),
+                vec!["Funds required for the action".to_string()],
+                vec![]
+            ),
+        ]
+    );
+
+    assert_eq!(
+        Config::get_json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "admin": { "type": "string", "description": "Contract admin address" },
+                "amount": { "type": "string", "description": "Funds required for the action" }
+            }
+        })
+    );
+}
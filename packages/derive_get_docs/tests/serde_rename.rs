@@ -0,0 +1,66 @@
+use derive_get_docs::GetDocs;
+use get_docs::{GetDocs, StructDoc};
+
+#[test]
+fn test_rename_all_snake_case_on_enum_and_fields() {
+    // The conventional CosmWasm message annotation: wire JSON variant keys are snake_case, not
+    // the PascalCase the Rust variant identifiers use. The container's `rename_all` does not
+    // apply to a struct variant's fields, which keep their snake_case Rust names.
+    #[derive(GetDocs)]
+    #[serde(rename_all = "snake_case")]
+    #[allow(dead_code)]
+    enum ExecuteMsg {
+        /// Increment the counter by `amount_to_add`
+        Increment {
+            /// How much to add to the counter
+            amount_to_add: u64,
+        },
+    }
+
+    assert_eq!(
+        ExecuteMsg::get_struct_docs(),
+        vec![StructDoc::new(
+            "increment".to_string(),
+            "increment".to_string(),
+            vec!["Increment the counter by `amount_to_add`".to_string()],
+            vec![StructDoc::new(
+                "amount_to_add".to_string(),
+                "u64".to_string(),
+                vec!["How much to add to the counter".to_string()],
+                vec![]
+            )]
+        )]
+    );
+}
+
+#[test]
+fn test_explicit_rename_overrides_rename_all() {
+    #[derive(GetDocs)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(dead_code)]
+    struct Config {
+        /// Contract admin address
+        #[serde(rename = "owner")]
+        admin: String,
+        /// Funds required for the action
+        amount_required: u64,
+    }
+
+    assert_eq!(
+        Config::get_struct_docs(),
+        vec![
+            StructDoc::new(
+                "owner".to_string(),
+                "String".to_string(),
+                vec!["Contract admin address".to_string()],
+                vec![]
+            ),
+            StructDoc::new(
+                "amountRequired".to_string(),
+                "u64".to_string(),
+                vec!["Funds required for the action".to_string()],
+                vec![]
+            ),
+        ]
+    );
+}
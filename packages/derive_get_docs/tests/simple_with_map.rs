@@ -1,6 +1,7 @@
 use derive_get_docs::GetDocs;
 use get_docs::{GetDocs, StructDoc};
 use pretty_assertions::assert_eq;
+use serde_json::json;
 use std::collections::HashMap;
 
 #[test]
@@ -52,3 +53,122 @@ fn test_simple_struct_with_map() {
         ),]
     );
 }
+
+#[test]
+fn test_simple_struct_with_map_json_schema_and_markdown() {
+    #[derive(GetDocs)]
+    struct Simple {
+        /// Name for simple example
+        #[allow(dead_code)]
+        name: String,
+
+        /// Length of something I'm not so sure what it's for
+        #[allow(dead_code)]
+        length: u64,
+    }
+
+    #[derive(GetDocs)]
+    struct SimpleMap {
+        /// Map for simple struct
+        #[allow(dead_code)]
+        simple: HashMap<String, Simple>,
+    }
+
+    assert_eq!(
+        SimpleMap::get_json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "simple": {
+                    "type": "object",
+                    "description": "Map for simple struct",
+                    "additionalProperties": {
+                        "type": "object",
+                        "properties": {
+                            "name": {
+                                "type": "string",
+                                "description": "Name for simple example"
+                            },
+                            "length": {
+                                "type": "integer",
+                                "description": "Length of something I'm not so sure what it's for"
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    assert_eq!(
+        SimpleMap::render_markdown(),
+        "\
+## SimpleMap
+
+| Field | Type | Description |
+| --- | --- | --- |
+| simple | HashMap < String, Simple > | Map for simple struct |
+
+### Simple
+
+| Field | Type | Description |
+| --- | --- | --- |
+| name | String | Name for simple example |
+| length | u64 | Length of something I'm not so sure what it's for |
+"
+    );
+}
+
+#[test]
+fn test_nested_option_vec_struct() {
+    #[derive(GetDocs)]
+    struct Config {
+        /// Value for config
+        #[allow(dead_code)]
+        value: String,
+    }
+
+    #[derive(GetDocs)]
+    struct Wrapper {
+        /// Optional list of configs
+        #[allow(dead_code)]
+        configs: Option<Vec<Config>>,
+    }
+
+    assert_eq!(
+        Wrapper::get_struct_docs(),
+        vec![StructDoc::new(
+            "configs".to_string(),
+            "Option < Vec < Config > >".to_string(),
+            vec!["Optional list of configs".to_string()],
+            vec![StructDoc::new(
+                "value".to_string(),
+                "String".to_string(),
+                vec!["Value for config".to_string()],
+                vec![]
+            )]
+        )]
+    );
+
+    assert_eq!(
+        Wrapper::get_json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "configs": {
+                    "type": "array",
+                    "description": "Optional list of configs",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "value": {
+                                "type": "string",
+                                "description": "Value for config"
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
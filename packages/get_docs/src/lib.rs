@@ -0,0 +1,184 @@
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructDoc {
+    pub name: String,
+    pub ty: String,
+    pub doc: Vec<String>,
+    pub fields: Vec<StructDoc>,
+}
+
+impl StructDoc {
+    pub fn new(name: String, ty: String, doc: Vec<String>, fields: Vec<StructDoc>) -> Self {
+        Self {
+            name,
+            ty,
+            doc,
+            fields,
+        }
+    }
+}
+
+pub trait GetDocs {
+    fn get_struct_docs() -> Vec<StructDoc>;
+
+    fn get_json_schema() -> Value
+    where
+        Self: Sized,
+    {
+        obj([
+            ("type", Value::String("object".to_string())),
+            ("properties", Value::Object(build_properties(&Self::get_struct_docs()))),
+        ])
+    }
+
+    fn render_markdown() -> String
+    where
+        Self: Sized,
+    {
+        let name = std::any::type_name::<Self>()
+            .rsplit("::")
+            .next()
+            .unwrap_or("Self")
+            .to_string();
+        let mut out = String::new();
+        render_section(&name, &Self::get_struct_docs(), 2, &mut out);
+        out
+    }
+}
+
+fn render_section(name: &str, fields: &[StructDoc], depth: usize, out: &mut String) {
+    out.push_str(&"#".repeat(depth));
+    out.push(' ');
+    out.push_str(name);
+    out.push_str("\n\n");
+    out.push_str("| Field | Type | Description |\n");
+    out.push_str("| --- | --- | --- |\n");
+    for field in fields {
+        out.push_str(&format!(
+            "| {} | {} | {} |\n",
+            field.name,
+            field.ty,
+            field.doc.join(" ")
+        ));
+    }
+
+    for field in fields {
+        if !field.fields.is_empty() {
+            out.push('\n');
+            render_section(&innermost_type_name(&field.ty), &field.fields, depth + 1, out);
+        }
+    }
+}
+
+fn build_properties(fields: &[StructDoc]) -> Map<String, Value> {
+    let mut map = Map::new();
+    for field in fields {
+        map.insert(field.name.clone(), field_schema(field));
+    }
+    map
+}
+
+fn field_schema(doc: &StructDoc) -> Value {
+    let mut schema = type_schema(&doc.ty, &doc.fields);
+    if let Value::Object(ref mut map) = schema {
+        map.insert("description".to_string(), Value::String(doc.doc.join(" ")));
+    }
+    schema
+}
+
+fn type_schema(ty: &str, fields: &[StructDoc]) -> Value {
+    if let Some((ident, args)) = parse_container(ty) {
+        match ident {
+            "Option" => return type_schema(args[0], fields),
+            "Vec" | "VecDeque" | "BTreeSet" | "HashSet" => {
+                return obj([
+                    ("type", Value::String("array".to_string())),
+                    ("items", type_schema(args[0], fields)),
+                ]);
+            }
+            "HashMap" | "BTreeMap" => {
+                let value_ty = args.last().copied().unwrap_or("String");
+                return obj([
+                    ("type", Value::String("object".to_string())),
+                    ("additionalProperties", type_schema(value_ty, fields)),
+                ]);
+            }
+            _ => {}
+        }
+    }
+
+    primitive_or_struct_schema(ty, fields)
+}
+
+fn primitive_or_struct_schema(ty: &str, fields: &[StructDoc]) -> Value {
+    match ty.trim() {
+        "String" | "str" | "char" => obj([("type", Value::String("string".to_string()))]),
+        "bool" => obj([("type", Value::String("boolean".to_string()))]),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+        | "isize" => obj([("type", Value::String("integer".to_string()))]),
+        "f32" | "f64" => obj([("type", Value::String("number".to_string()))]),
+        _ if fields.is_empty() => obj([("type", Value::String("string".to_string()))]),
+        _ => obj([
+            ("type", Value::String("object".to_string())),
+            ("properties", Value::Object(build_properties(fields))),
+        ]),
+    }
+}
+
+fn obj<const N: usize>(entries: [(&str, Value); N]) -> Value {
+    Value::Object(
+        entries
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect(),
+    )
+}
+
+fn innermost_type_name(ty: &str) -> String {
+    if let Some((ident, args)) = parse_container(ty) {
+        match ident {
+            "Option" | "Vec" | "VecDeque" | "BTreeSet" | "HashSet" => {
+                return innermost_type_name(args[0])
+            }
+            "HashMap" | "BTreeMap" => {
+                if let Some(value_ty) = args.last() {
+                    return innermost_type_name(value_ty);
+                }
+            }
+            _ => {}
+        }
+    }
+    ty.trim().to_string()
+}
+
+fn parse_container(ty: &str) -> Option<(&str, Vec<&str>)> {
+    let ty = ty.trim();
+    let lt = ty.find('<')?;
+    let gt = ty.rfind('>')?;
+    if gt < lt {
+        return None;
+    }
+    let ident = ty[..lt].trim();
+    let inner = &ty[lt + 1..gt];
+    Some((ident, split_top_level(inner)))
+}
+
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut depth = 0;
+    let mut start = 0;
+    let mut parts = Vec::new();
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(s[start..].trim());
+    parts
+}